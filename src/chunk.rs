@@ -1,7 +1,11 @@
 use crate::chunk_type::ChunkType;
 use std::str::{FromStr, from_utf8};
 use std::fmt::{self, Display, Formatter};
+use std::io::{Read, Write};
 use crc32fast;
+use flate2::Compression;
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
 
 pub struct Chunk {
     length: u32,
@@ -12,32 +16,39 @@ pub struct Chunk {
 
 impl Chunk {
     pub fn new(chunk_type: ChunkType, data: Vec<u8>) -> Self {
-        unimplemented!()
+        let length = data.len() as u32;
+
+        let mut hasher = crc32fast::Hasher::new();
+        hasher.update(&chunk_type.bytes());
+        hasher.update(&data);
+        let crc = hasher.finalize();
+
+        Self { length, chunk_type, data, crc }
     }
 
-    fn length(&self) -> u32 {
+    pub fn length(&self) -> u32 {
         self.length
     }
 
-    fn chunk_type(&self) -> &ChunkType {
+    pub fn chunk_type(&self) -> &ChunkType {
         &self.chunk_type
     }
 
-    fn data(&self) -> &[u8] {
+    pub fn data(&self) -> &[u8] {
         &self.data
     }
 
-    fn crc(&self) -> u32 {
+    pub fn crc(&self) -> u32 {
         self.crc
     }
 
-    fn data_as_string(&self) -> Result<String, String> {
+    pub fn data_as_string(&self) -> Result<String, String> {
        let t =  from_utf8(&self.data).unwrap();
        let st = t.to_string();
        Ok(st)
     }
 
-    fn as_bytes(&self) -> Vec<u8> {
+    pub fn as_bytes(&self) -> Vec<u8> {
         let mut temp: Vec<u8> = Vec::new();
         
         let length_bytes: [u8; 4] = self.length.to_be_bytes();
@@ -48,7 +59,58 @@ impl Chunk {
         temp.extend_from_slice(&chunk_type_bytes);
         temp.extend_from_slice(&self.data);
         temp.extend_from_slice(&crc_bytes);
-        temp 
+        temp
+    }
+
+    /// Upper bound on a single chunk's decompressed size, independent of what
+    /// a chunk's `original_len` prefix claims. Without this, a small compressed
+    /// chunk that genuinely inflates to gigabytes (DEFLATE can exceed 1000:1)
+    /// would still force an unbounded allocation.
+    pub const MAX_DECOMPRESSED_SIZE: u32 = 64 * 1024 * 1024;
+
+    pub fn new_compressed(chunk_type: ChunkType, data: Vec<u8>) -> Self {
+        let original_len = data.len() as u32;
+
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&data).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let mut stored: Vec<u8> = Vec::with_capacity(4 + compressed.len());
+        stored.extend_from_slice(&original_len.to_be_bytes());
+        stored.extend_from_slice(&compressed);
+
+        Self::new(chunk_type, stored)
+    }
+
+    pub fn decompressed_data(&self) -> Result<Vec<u8>, ChunkError> {
+        if self.data.len() < 4 {
+            return Err(ChunkError::Decompression);
+        }
+
+        let original_len_bytes: [u8; 4] = match self.data[0..4].try_into() {
+            Ok(v) => v,
+            Err(_) => return Err(ChunkError::Decompression)
+        };
+        let original_len = u32::from_be_bytes(original_len_bytes);
+
+        if original_len > Self::MAX_DECOMPRESSED_SIZE {
+            return Err(ChunkError::Decompression);
+        }
+
+        let decoder = DeflateDecoder::new(&self.data[4..]);
+        // Bound inflate output to one byte past the declared length so a chunk
+        // can't claim a small original_len while actually expanding without limit.
+        let mut limited = decoder.take(original_len as u64 + 1);
+        let mut decompressed: Vec<u8> = Vec::new();
+        if limited.read_to_end(&mut decompressed).is_err() {
+            return Err(ChunkError::Decompression);
+        }
+
+        if decompressed.len() != original_len as usize {
+            return Err(ChunkError::Decompression);
+        }
+
+        Ok(decompressed)
     }
 }
 
@@ -85,9 +147,27 @@ impl TryFrom<&[u8]> for Chunk {
 
         let chunk_data_bytes = value[8..data_end].to_vec();
 
-        let crc = crc32fast::hash(&chunk_data_bytes);
+        let crc_end = data_end + 4;
+        if value.len() < crc_end {
+            return Err(ChunkError::InvalidArray);
+        }
+
+        let crc_bytes: [u8; 4] = match value[data_end..crc_end].try_into() {
+            Ok(v) => v,
+            Err(_) => return Err(ChunkError::InvalidArray)
+        };
+        let expected = u32::from_be_bytes(crc_bytes);
+
+        let mut hasher = crc32fast::Hasher::new();
+        hasher.update(&chunk_type.bytes());
+        hasher.update(&chunk_data_bytes);
+        let actual = hasher.finalize();
+
+        if actual != expected {
+            return Err(ChunkError::CrcMismatch { expected, actual });
+        }
 
-        Ok(Self {length, chunk_type, data: chunk_data_bytes, crc })
+        Ok(Self {length, chunk_type, data: chunk_data_bytes, crc: actual })
     }
 }
 
@@ -106,14 +186,23 @@ impl Display for Chunk {
 #[derive(Debug)]
 pub enum ChunkError {
     InvalidArray,
-    InvalidString
+    InvalidString,
+    CrcMismatch { expected: u32, actual: u32 },
+    Decompression
 }
 impl std::error::Error for ChunkError {}
 
 
 impl Display for ChunkError {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "invalid chunk type")
+        match self {
+            ChunkError::InvalidArray => write!(f, "invalid chunk byte array"),
+            ChunkError::InvalidString => write!(f, "invalid chunk string"),
+            ChunkError::CrcMismatch { expected, actual } => {
+                write!(f, "crc mismatch: expected {}, got {}", expected, actual)
+            },
+            ChunkError::Decompression => write!(f, "malformed compressed chunk data"),
+        }
     }
 }
 
@@ -242,7 +331,51 @@ mod tests {
             .collect();
         
         let chunk: Chunk = TryFrom::try_from(chunk_data.as_ref()).unwrap();
-        
+
         let _chunk_string = format!("{}", chunk);
     }
+
+    #[test]
+    fn test_new_compressed_round_trips() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let data = "This is where your secret message will be!".repeat(10).into_bytes();
+
+        let chunk = Chunk::new_compressed(chunk_type, data.clone());
+
+        assert_eq!(chunk.decompressed_data().unwrap(), data);
+    }
+
+    #[test]
+    fn test_new_compressed_shrinks_repetitive_data() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let data = vec![b'a'; 1000];
+
+        let chunk = Chunk::new_compressed(chunk_type, data);
+
+        assert!(chunk.data().len() < 1000);
+    }
+
+    #[test]
+    fn test_decompressed_data_rejects_malformed_stream() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let chunk = Chunk::new(chunk_type, vec![0, 0, 0, 4, 1, 2, 3]);
+
+        assert!(chunk.decompressed_data().is_err());
+    }
+
+    #[test]
+    fn test_decompressed_data_rejects_oversized_claimed_length() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let compressed = Chunk::new_compressed(chunk_type, b"hello".to_vec());
+
+        // Tamper the stored original-length prefix to claim a payload far
+        // beyond the independent cap, regardless of what the stream actually holds.
+        let mut data = compressed.data().to_vec();
+        data[0..4].copy_from_slice(&(Chunk::MAX_DECOMPRESSED_SIZE + 1).to_be_bytes());
+
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let chunk = Chunk::new(chunk_type, data);
+
+        assert!(chunk.decompressed_data().is_err());
+    }
 }
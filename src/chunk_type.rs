@@ -1,5 +1,5 @@
 
-use std::str::FromStr;
+use std::str::{from_utf8, FromStr};
 use std::fmt::{self, Display, Formatter};
 
 #[derive(Debug)]
@@ -57,6 +57,16 @@ impl FromStr for ChunkType {
     }
 }
 
+impl Display for ChunkType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let s = match from_utf8(&self.0) {
+            Ok(s) => s,
+            Err(_) => return Err(fmt::Error)
+        };
+        write!(f, "{}", s)
+    }
+}
+
 impl PartialEq for ChunkType {
     fn eq(&self, other: &Self) -> bool {
         unimplemented!()
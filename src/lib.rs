@@ -0,0 +1,4 @@
+pub mod chunk;
+pub mod chunk_type;
+pub mod payload;
+pub mod png;
@@ -0,0 +1,351 @@
+use std::fmt::{self, Display, Formatter};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Item {
+    Bytes(Vec<u8>),
+    List(Vec<Item>)
+}
+
+impl Drop for Item {
+    // The default derived drop glue recurses one stack frame per level of
+    // nesting, so a deeply nested List (decoded straight from an untrusted
+    // chunk) can overflow the stack on drop even if it was built and parsed
+    // iteratively. Detach each level's children into a work queue first so
+    // no single drop call recurses into its own descendants.
+    fn drop(&mut self) {
+        let mut pending: Vec<Vec<Item>> = Vec::new();
+
+        if let Item::List(items) = self {
+            pending.push(std::mem::take(items));
+        }
+
+        while let Some(items) = pending.pop() {
+            for mut item in items {
+                if let Item::List(inner) = &mut item {
+                    pending.push(std::mem::take(inner));
+                }
+            }
+        }
+    }
+}
+
+// encode/decode walk the Item tree with an explicit work stack instead of
+// native recursion: a chain of tens of thousands of nested lists is a
+// trivially small payload that would otherwise blow the call stack (an
+// unrecoverable process abort, not a catchable panic) on both paths.
+
+pub fn encode(item: &Item) -> Vec<u8> {
+    enum Frame<'a> {
+        Visit(&'a Item),
+        Collect(usize)
+    }
+
+    let mut work: Vec<Frame> = vec![Frame::Visit(item)];
+    let mut output: Vec<Vec<u8>> = Vec::new();
+
+    while let Some(frame) = work.pop() {
+        match frame {
+            Frame::Visit(Item::Bytes(bytes)) => {
+                output.push(encode_bytes(bytes));
+            },
+            Frame::Visit(Item::List(items)) => {
+                work.push(Frame::Collect(items.len()));
+                for child in items.iter().rev() {
+                    work.push(Frame::Visit(child));
+                }
+            },
+            Frame::Collect(count) => {
+                let start = output.len() - count;
+                let payload: Vec<u8> = output.split_off(start).into_iter().flatten().collect();
+                output.push(wrap_list_payload(payload));
+            }
+        }
+    }
+
+    output.pop().unwrap_or_default()
+}
+
+pub fn decode(data: &[u8]) -> Result<Item, PayloadError> {
+    if data.is_empty() {
+        return Err(PayloadError::UnexpectedEnd);
+    }
+
+    let mut items = decode_items(data)?;
+
+    if items.len() != 1 {
+        return Err(PayloadError::TrailingData);
+    }
+
+    Ok(items.remove(0))
+}
+
+fn encode_bytes(bytes: &[u8]) -> Vec<u8> {
+    if bytes.len() == 1 && bytes[0] < 0x80 {
+        return bytes.to_vec();
+    }
+
+    let mut out: Vec<u8> = Vec::new();
+
+    if bytes.len() <= 55 {
+        out.push(0x80 + bytes.len() as u8);
+    } else {
+        let len_bytes = be_bytes(bytes.len());
+        out.push(0xB7 + len_bytes.len() as u8);
+        out.extend_from_slice(&len_bytes);
+    }
+
+    out.extend_from_slice(bytes);
+    out
+}
+
+fn wrap_list_payload(payload: Vec<u8>) -> Vec<u8> {
+    let mut out: Vec<u8> = Vec::with_capacity(payload.len() + 9);
+
+    if payload.len() <= 55 {
+        out.push(0xC0 + payload.len() as u8);
+    } else {
+        let len_bytes = be_bytes(payload.len());
+        out.push(0xF7 + len_bytes.len() as u8);
+        out.extend_from_slice(&len_bytes);
+    }
+
+    out.extend_from_slice(&payload);
+    out
+}
+
+// Parses the flat sequence of items in `data` (a top-level payload, or a
+// list's inner payload) using an explicit frame stack in place of recursion:
+// each frame tracks the bytes still to parse and the items decoded so far
+// for one level of list nesting.
+fn decode_items(data: &[u8]) -> Result<Vec<Item>, PayloadError> {
+    struct Frame<'a> {
+        remaining: &'a [u8],
+        items: Vec<Item>
+    }
+
+    let mut stack: Vec<Frame> = vec![Frame { remaining: data, items: Vec::new() }];
+
+    loop {
+        let top = stack.len() - 1;
+
+        if stack[top].remaining.is_empty() {
+            let finished = stack.pop().unwrap();
+
+            match stack.last_mut() {
+                Some(parent) => {
+                    parent.items.push(Item::List(finished.items));
+                    continue;
+                },
+                None => return Ok(finished.items)
+            }
+        }
+
+        let cursor = stack[top].remaining;
+        let prefix = cursor[0];
+
+        match prefix {
+            0x00..=0x7F => {
+                stack[top].items.push(Item::Bytes(vec![prefix]));
+                stack[top].remaining = &cursor[1..];
+            },
+            0x80..=0xB7 => {
+                let len = (prefix - 0x80) as usize;
+                let end = 1 + len;
+                if cursor.len() < end { return Err(PayloadError::UnexpectedEnd); }
+                stack[top].items.push(Item::Bytes(cursor[1..end].to_vec()));
+                stack[top].remaining = &cursor[end..];
+            },
+            0xB8..=0xBF => {
+                let len_of_len = (prefix - 0xB7) as usize;
+                let len_end = 1 + len_of_len;
+                if cursor.len() < len_end { return Err(PayloadError::UnexpectedEnd); }
+                let len = usize_from_be_bytes(&cursor[1..len_end])?;
+                let end = len_end + len;
+                if cursor.len() < end { return Err(PayloadError::UnexpectedEnd); }
+                stack[top].items.push(Item::Bytes(cursor[len_end..end].to_vec()));
+                stack[top].remaining = &cursor[end..];
+            },
+            0xC0..=0xF7 => {
+                let payload_len = (prefix - 0xC0) as usize;
+                let end = 1 + payload_len;
+                if cursor.len() < end { return Err(PayloadError::UnexpectedEnd); }
+                stack[top].remaining = &cursor[end..];
+                stack.push(Frame { remaining: &cursor[1..end], items: Vec::new() });
+            },
+            0xF8..=0xFF => {
+                let len_of_len = (prefix - 0xF7) as usize;
+                let len_end = 1 + len_of_len;
+                if cursor.len() < len_end { return Err(PayloadError::UnexpectedEnd); }
+                let payload_len = usize_from_be_bytes(&cursor[1..len_end])?;
+                let end = len_end + payload_len;
+                if cursor.len() < end { return Err(PayloadError::UnexpectedEnd); }
+                stack[top].remaining = &cursor[end..];
+                stack.push(Frame { remaining: &cursor[len_end..end], items: Vec::new() });
+            }
+        }
+    }
+}
+
+fn be_bytes(mut value: usize) -> Vec<u8> {
+    if value == 0 {
+        return vec![0];
+    }
+
+    let mut bytes: Vec<u8> = Vec::new();
+    while value > 0 {
+        bytes.push((value & 0xFF) as u8);
+        value >>= 8;
+    }
+    bytes.reverse();
+    bytes
+}
+
+fn usize_from_be_bytes(bytes: &[u8]) -> Result<usize, PayloadError> {
+    if bytes.len() > 8 {
+        return Err(PayloadError::LengthOverflow);
+    }
+
+    let mut buf = [0u8; 8];
+    buf[8 - bytes.len()..].copy_from_slice(bytes);
+    Ok(u64::from_be_bytes(buf) as usize)
+}
+
+#[derive(Debug)]
+pub enum PayloadError {
+    UnexpectedEnd,
+    TrailingData,
+    LengthOverflow
+}
+impl std::error::Error for PayloadError {}
+
+impl Display for PayloadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            PayloadError::UnexpectedEnd => write!(f, "unexpected end of payload"),
+            PayloadError::TrailingData => write!(f, "trailing data after payload"),
+            PayloadError::LengthOverflow => write!(f, "payload length too large"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_single_byte() {
+        let item = Item::Bytes(vec![0x42]);
+        assert_eq!(encode(&item), vec![0x42]);
+    }
+
+    #[test]
+    fn test_encode_short_string() {
+        let item = Item::Bytes(b"dog".to_vec());
+        assert_eq!(encode(&item), vec![0x83, b'd', b'o', b'g']);
+    }
+
+    #[test]
+    fn test_encode_empty_string() {
+        let item = Item::Bytes(vec![]);
+        assert_eq!(encode(&item), vec![0x80]);
+    }
+
+    #[test]
+    fn test_encode_long_string() {
+        let data = vec![b'a'; 56];
+        let item = Item::Bytes(data.clone());
+        let encoded = encode(&item);
+
+        assert_eq!(encoded[0], 0xB7 + 1);
+        assert_eq!(encoded[1], 56);
+        assert_eq!(&encoded[2..], data.as_slice());
+    }
+
+    #[test]
+    fn test_encode_empty_list() {
+        let item = Item::List(vec![]);
+        assert_eq!(encode(&item), vec![0xC0]);
+    }
+
+    #[test]
+    fn test_encode_short_list() {
+        let item = Item::List(vec![Item::Bytes(b"cat".to_vec()), Item::Bytes(b"dog".to_vec())]);
+        assert_eq!(
+            encode(&item),
+            vec![0xC8, 0x83, b'c', b'a', b't', 0x83, b'd', b'o', b'g']
+        );
+    }
+
+    #[test]
+    fn test_round_trip_nested_list() {
+        let item = Item::List(vec![
+            Item::Bytes(b"alice".to_vec()),
+            Item::Bytes(1_700_000_000u32.to_be_bytes().to_vec()),
+            Item::List(vec![
+                Item::Bytes(b"photo.png".to_vec()),
+                Item::Bytes(vec![1, 2, 3, 4])
+            ])
+        ]);
+
+        let encoded = encode(&item);
+        let decoded = decode(&encoded).unwrap();
+
+        assert_eq!(decoded, item);
+    }
+
+    #[test]
+    fn test_round_trip_long_string() {
+        let item = Item::Bytes(vec![7u8; 1000]);
+        let encoded = encode(&item);
+        let decoded = decode(&encoded).unwrap();
+
+        assert_eq!(decoded, item);
+    }
+
+    #[test]
+    fn test_decode_truncated_input_errors() {
+        let item = Item::Bytes(b"dog".to_vec());
+        let mut encoded = encode(&item);
+        encoded.pop();
+
+        assert!(decode(&encoded).is_err());
+    }
+
+    #[test]
+    fn test_decode_trailing_garbage_errors() {
+        let item = Item::Bytes(b"dog".to_vec());
+        let mut encoded = encode(&item);
+        encoded.push(0xFF);
+
+        assert!(decode(&encoded).is_err());
+    }
+
+    #[test]
+    fn test_deeply_nested_list_does_not_overflow_stack() {
+        // Derived PartialEq recurses per level, so this deliberately avoids
+        // `==` on the full tree and instead walks the single-child chain
+        // iteratively to confirm the decoded depth matches. Both trees are
+        // dropped at the end of the test, which exercises Item's iterative Drop.
+        fn nesting_depth(mut item: &Item) -> usize {
+            let mut depth = 0;
+            while let Item::List(items) = item {
+                match items.as_slice() {
+                    [only] => { depth += 1; item = only; },
+                    _ => break
+                }
+            }
+            depth
+        }
+
+        let depth = 50_000;
+        let mut item = Item::List(vec![]);
+        for _ in 0..depth {
+            item = Item::List(vec![item]);
+        }
+
+        let encoded = encode(&item);
+        let decoded = decode(&encoded).unwrap();
+
+        assert_eq!(nesting_depth(&decoded), depth);
+    }
+}
@@ -0,0 +1,209 @@
+use crate::chunk::Chunk;
+use std::fmt::{self, Display, Formatter};
+
+pub struct Png {
+    chunks: Vec<Chunk>
+}
+
+impl Png {
+    pub const STANDARD_HEADER: [u8; 8] = [137, 80, 78, 71, 13, 10, 26, 10];
+
+    pub fn from_chunks(chunks: Vec<Chunk>) -> Self {
+        Self { chunks }
+    }
+
+    pub fn append_chunk(&mut self, chunk: Chunk) {
+        self.chunks.push(chunk);
+    }
+
+    pub fn remove_first_chunk(&mut self, chunk_type: &str) -> Result<Chunk, PngError> {
+        let index = self.chunks
+            .iter()
+            .position(|c| c.chunk_type().bytes() == chunk_type.as_bytes());
+
+        let index = match index {
+            Some(i) => i,
+            None => return Err(PngError::ChunkNotFound)
+        };
+
+        Ok(self.chunks.remove(index))
+    }
+
+    pub fn chunk_by_type(&self, chunk_type: &str) -> Option<&Chunk> {
+        self.chunks
+            .iter()
+            .find(|c| c.chunk_type().bytes() == chunk_type.as_bytes())
+    }
+
+    pub fn chunks(&self) -> &[Chunk] {
+        &self.chunks
+    }
+
+    pub fn as_bytes(&self) -> Vec<u8> {
+        let mut temp: Vec<u8> = Vec::new();
+
+        temp.extend_from_slice(&Self::STANDARD_HEADER);
+        for chunk in &self.chunks {
+            temp.extend_from_slice(&chunk.as_bytes());
+        }
+
+        temp
+    }
+}
+
+impl TryFrom<&[u8]> for Png {
+    type Error = PngError;
+
+    fn try_from(value: &[u8]) -> Result<Self, PngError> {
+        if value.len() < Png::STANDARD_HEADER.len() {
+            return Err(PngError::InvalidHeader);
+        }
+
+        if value[..Png::STANDARD_HEADER.len()] != Png::STANDARD_HEADER {
+            return Err(PngError::InvalidHeader);
+        }
+
+        let mut chunks: Vec<Chunk> = Vec::new();
+        let mut remaining = &value[Png::STANDARD_HEADER.len()..];
+
+        while !remaining.is_empty() {
+            if remaining.len() < 12 {
+                return Err(PngError::InvalidArray);
+            }
+
+            let length_bytes: [u8; 4] = match remaining[0..4].try_into() {
+                Ok(v) => v,
+                Err(_) => return Err(PngError::InvalidArray)
+            };
+            let length = u32::from_be_bytes(length_bytes) as usize;
+
+            let chunk_end = 4 + 4 + length + 4;
+            if remaining.len() < chunk_end {
+                return Err(PngError::InvalidArray);
+            }
+
+            let chunk = match Chunk::try_from(&remaining[..chunk_end]) {
+                Ok(c) => c,
+                Err(_) => return Err(PngError::InvalidArray)
+            };
+
+            chunks.push(chunk);
+            remaining = &remaining[chunk_end..];
+        }
+
+        Ok(Self { chunks })
+    }
+}
+
+#[derive(Debug)]
+pub enum PngError {
+    InvalidHeader,
+    InvalidArray,
+    ChunkNotFound
+}
+impl std::error::Error for PngError {}
+
+impl Display for PngError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            PngError::InvalidHeader => write!(f, "invalid png header"),
+            PngError::InvalidArray => write!(f, "invalid png byte array"),
+            PngError::ChunkNotFound => write!(f, "chunk not found"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chunk_type::ChunkType;
+    use std::str::FromStr;
+
+    fn testing_chunk(chunk_type: &str, data: &str) -> Chunk {
+        let chunk_type = ChunkType::from_str(chunk_type).unwrap();
+        Chunk::new(chunk_type, data.as_bytes().to_vec())
+    }
+
+    fn testing_png() -> Png {
+        let chunks = vec![
+            testing_chunk("FrSt", "I am the first chunk"),
+            testing_chunk("miDl", "I am another chunk"),
+            testing_chunk("LASt", "I am the last chunk"),
+        ];
+
+        Png::from_chunks(chunks)
+    }
+
+    #[test]
+    fn test_from_chunks() {
+        let png = testing_png();
+        assert_eq!(png.chunks().len(), 3);
+    }
+
+    #[test]
+    fn test_valid_png_from_bytes() {
+        let png = testing_png();
+        let bytes = png.as_bytes();
+
+        let png = Png::try_from(bytes.as_ref()).unwrap();
+        assert_eq!(png.chunks().len(), 3);
+    }
+
+    #[test]
+    fn test_invalid_header() {
+        let bytes = [13, 80, 78, 71, 13, 10, 26, 10];
+        let png = Png::try_from(bytes.as_ref());
+        assert!(png.is_err());
+    }
+
+    #[test]
+    fn test_invalid_chunk() {
+        let mut bytes = Png::STANDARD_HEADER.to_vec();
+        bytes.extend_from_slice(&[0, 0, 0, 5]);
+        let png = Png::try_from(bytes.as_ref());
+        assert!(png.is_err());
+    }
+
+    #[test]
+    fn test_append_chunk() {
+        let mut png = testing_png();
+        png.append_chunk(testing_chunk("TeSt", "Message"));
+
+        assert_eq!(png.chunks().len(), 4);
+        assert_eq!(png.chunk_by_type("TeSt").unwrap().data_as_string().unwrap(), "Message");
+    }
+
+    #[test]
+    fn test_remove_chunk() {
+        let mut png = testing_png();
+        let removed = png.remove_first_chunk("miDl").unwrap();
+
+        assert_eq!(removed.data_as_string().unwrap(), "I am another chunk");
+        assert_eq!(png.chunks().len(), 2);
+        assert!(png.chunk_by_type("miDl").is_none());
+    }
+
+    #[test]
+    fn test_remove_missing_chunk() {
+        let mut png = testing_png();
+        assert!(png.remove_first_chunk("nope").is_err());
+    }
+
+    #[test]
+    fn test_chunk_by_type() {
+        let png = testing_png();
+        let chunk = png.chunk_by_type("FrSt").unwrap();
+        assert_eq!(chunk.data_as_string().unwrap(), "I am the first chunk");
+    }
+
+    #[test]
+    fn test_png_as_bytes_round_trip() {
+        let png = testing_png();
+        let bytes = png.as_bytes();
+
+        assert_eq!(&bytes[0..8], &Png::STANDARD_HEADER);
+
+        let decoded = Png::try_from(bytes.as_ref()).unwrap();
+        assert_eq!(decoded.as_bytes(), bytes);
+    }
+}